@@ -12,14 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! A lightweight library which provides a fluent interface for generating SHA-1 and SHA-2 digests.
+//! A lightweight library which provides a fluent interface for generating SHA-1, SHA-2, SHA-3
+//! and BLAKE2 digests.
 
+use std::array::TryFromSliceError;
+use std::convert::TryInto;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{self, Read};
 use ring::digest as r_digest;
+use sha3::Digest as _;
 
+mod sha2_const;
 
-/// The hashing algorithm. SHA-1 and SHA2 algorithms are supported.
+
+/// The hashing algorithm. SHA-1, SHA-2, SHA-3 and BLAKE2 algorithms are supported.
 #[derive(Debug, Eq, PartialEq)]
 pub enum Hashing {
     /// The SHA1 hash algorithm. Should generally be avoided unless working with legacy software.
@@ -32,6 +38,26 @@ pub enum Hashing {
     Sha512,
     /// The SHA2 512-256 bit hash algorithm. Uses SHA-512 but returns only 256 bits.
     Sha512_256,
+    /// The SHA3 224 bit hash algorithm.
+    Sha3_224,
+    /// The SHA3 256 bit hash algorithm.
+    Sha3_256,
+    /// The SHA3 384 bit hash algorithm.
+    Sha3_384,
+    /// The SHA3 512 bit hash algorithm.
+    Sha3_512,
+    /// The SHAKE128 extendable-output function. Use `new_xof_context` rather than `new_context`
+    /// to produce a digest of a caller-chosen length.
+    Shake128,
+    /// The SHAKE256 extendable-output function. Use `new_xof_context` rather than `new_context`
+    /// to produce a digest of a caller-chosen length.
+    Shake256,
+    /// The BLAKE2b hash algorithm with a 512 bit digest. A faster alternative to the SHA-2
+    /// family for large inputs.
+    Blake2b512,
+    /// The BLAKE2s hash algorithm with a 256 bit digest. A faster alternative to the SHA-2
+    /// family for large inputs, optimised for 8 to 32 bit platforms.
+    Blake2s256,
 }
 
 impl Hashing {
@@ -39,16 +65,47 @@ impl Hashing {
     /// Creates a new instance of a `HashContext` to be used with the selected `Hashing` algorithm.
     pub fn new_context(&self) -> HashContext {
         match self {
-            Self::Sha1 => HashContext(r_digest::Context::new(
-                &r_digest::SHA1_FOR_LEGACY_USE_ONLY)),
-            Self::Sha256 => HashContext(r_digest::Context::new(
-                &r_digest::SHA256)),
-            Self::Sha384 => HashContext(r_digest::Context::new(
-                &r_digest::SHA384)),
-            Self::Sha512 => HashContext(r_digest::Context::new(
-                &r_digest::SHA512)),
-            Self::Sha512_256 => HashContext(r_digest::Context::new(
-                &r_digest::SHA512_256)),
+            Self::Sha1 => HashContext(ContextInner::Ring(r_digest::Context::new(
+                &r_digest::SHA1_FOR_LEGACY_USE_ONLY))),
+            Self::Sha256 => HashContext(ContextInner::Ring(r_digest::Context::new(
+                &r_digest::SHA256))),
+            Self::Sha384 => HashContext(ContextInner::Ring(r_digest::Context::new(
+                &r_digest::SHA384))),
+            Self::Sha512 => HashContext(ContextInner::Ring(r_digest::Context::new(
+                &r_digest::SHA512))),
+            Self::Sha512_256 => HashContext(ContextInner::Ring(r_digest::Context::new(
+                &r_digest::SHA512_256))),
+            Self::Sha3_224 => HashContext(ContextInner::Sha3_224(sha3::Sha3_224::new())),
+            Self::Sha3_256 => HashContext(ContextInner::Sha3_256(sha3::Sha3_256::new())),
+            Self::Sha3_384 => HashContext(ContextInner::Sha3_384(sha3::Sha3_384::new())),
+            Self::Sha3_512 => HashContext(ContextInner::Sha3_512(sha3::Sha3_512::new())),
+            Self::Blake2b512 => HashContext(ContextInner::Blake2b512(blake2::Blake2b512::new())),
+            Self::Blake2s256 => HashContext(ContextInner::Blake2s256(blake2::Blake2s256::new())),
+            Self::Shake128 | Self::Shake256 => panic!(
+                "{:?} is an extendable-output function; use new_xof_context instead of new_context", self),
+        }
+    }
+
+    /// Creates a new instance of an `XofContext` to be used with the selected extendable-output
+    /// function. Only `Shake128` and `Shake256` support this; unlike `new_context`, the
+    /// resulting digest length isn't fixed by the algorithm but chosen by the caller when
+    /// calling `XofContext::finish`.
+    pub fn new_xof_context(&self) -> XofContext {
+        match self {
+            Self::Shake128 => XofContext(XofInner::Shake128(sha3::Shake128::default())),
+            Self::Shake256 => XofContext(XofInner::Shake256(sha3::Shake256::default())),
+            _ => panic!("{:?} is not an extendable-output function; use new_context instead of new_xof_context", self),
+        }
+    }
+
+    /// Computes a digest of `data` at compile time, usable in a `const` expression. `ring`
+    /// cannot run in a `const` context, so this is backed by a pure-Rust implementation of the
+    /// SHA-2 compression function instead. Only `Sha256` and `Sha512` support this.
+    pub const fn const_hash(&self, data: &[u8]) -> ConstHash {
+        match self {
+            Self::Sha256 => ConstHash::Bytes32(sha2_const::sha256(data)),
+            Self::Sha512 => ConstHash::Bytes64(sha2_const::sha512(data)),
+            _ => panic!("const_hash is only supported for Hashing::Sha256 and Hashing::Sha512"),
         }
     }
 
@@ -72,42 +129,142 @@ impl Hashing {
     }
 
     /// Returns a `Hash` of the file located at the given path.
-    /// Fails if the file doesn't exist or can't be opened.
-    pub fn hash_file(&self, path: &str) -> Hash {
-        // TODO: improve the error handling here to allow catching errors without panic
-        let file = File::open(path).expect(&format!("Failed to open file with path: {}", path));
-        let reader = BufReader::new(file);
+    /// Returns an `io::Error` if the file doesn't exist or can't be read.
+    pub fn hash_file(&self, path: &str) -> io::Result<Hash> {
+        self.hash_reader(File::open(path)?)
+    }
 
+    /// Returns a `Hash` of all the bytes read from `reader` until EOF.
+    /// Returns an `io::Error` if reading from `reader` fails.
+    pub fn hash_reader<R: Read>(&self, mut reader: R) -> io::Result<Hash> {
         let mut ctx = self.new_context();
-        for line in reader.lines() {
-            ctx.update(line.unwrap().as_bytes());
+        let mut buf = [0u8; 8192];
+        loop {
+            let n = reader.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            ctx.update(&buf[..n]);
         }
 
-        ctx.finish()
+        Ok(ctx.finish())
     }
 
 }
 
 
 
+/// The backend-specific state backing a `HashContext`. `ring` doesn't expose SHA-3, so the
+/// SHA-3 variants are driven by the `sha3` crate instead.
+#[derive(Clone)]
+enum ContextInner {
+    Ring(r_digest::Context),
+    Sha3_224(sha3::Sha3_224),
+    Sha3_256(sha3::Sha3_256),
+    Sha3_384(sha3::Sha3_384),
+    Sha3_512(sha3::Sha3_512),
+    Blake2b512(blake2::Blake2b512),
+    Blake2s256(blake2::Blake2s256),
+}
+
 /// A context to be used for multi-step hash calculations.
 /// Useful when hashing a data structure with multiple fields or when hashing larger inputs.
 #[derive(Clone)]
-pub struct HashContext(r_digest::Context);
+pub struct HashContext(ContextInner);
 
 impl HashContext {
 
     /// Updates the `HashContext` with the given byte array `data`.
     #[inline]
     pub fn update(&mut self, data: &[u8]) {
-        self.0.update(data);
+        match &mut self.0 {
+            ContextInner::Ring(ctx) => ctx.update(data),
+            ContextInner::Sha3_224(ctx) => ctx.update(data),
+            ContextInner::Sha3_256(ctx) => ctx.update(data),
+            ContextInner::Sha3_384(ctx) => ctx.update(data),
+            ContextInner::Sha3_512(ctx) => ctx.update(data),
+            ContextInner::Blake2b512(ctx) => ctx.update(data),
+            ContextInner::Blake2s256(ctx) => ctx.update(data),
+        }
     }
 
     /// Returns the `Hash` from the data in the `HashContext`.
     /// Consumes the `HashContext` so it cannot reused after calling finish.
     #[inline]
     pub fn finish(self) -> Hash {
-        Hash(self.0.finish())
+        match self.0 {
+            ContextInner::Ring(ctx) => Hash(ctx.finish().as_ref().to_vec()),
+            ContextInner::Sha3_224(ctx) => Hash(ctx.finalize().to_vec()),
+            ContextInner::Sha3_256(ctx) => Hash(ctx.finalize().to_vec()),
+            ContextInner::Sha3_384(ctx) => Hash(ctx.finalize().to_vec()),
+            ContextInner::Sha3_512(ctx) => Hash(ctx.finalize().to_vec()),
+            ContextInner::Blake2b512(ctx) => Hash(ctx.finalize().to_vec()),
+            ContextInner::Blake2s256(ctx) => Hash(ctx.finalize().to_vec()),
+        }
+    }
+
+}
+
+
+/// The backend-specific state backing an `XofContext`.
+#[derive(Clone)]
+enum XofInner {
+    Shake128(sha3::Shake128),
+    Shake256(sha3::Shake256),
+}
+
+/// A context to be used for multi-step extendable-output function (XOF) calculations, such as
+/// SHAKE128 or SHAKE256. Unlike `HashContext`, the digest length isn't fixed by the algorithm;
+/// it's chosen by the caller when calling `finish`.
+#[derive(Clone)]
+pub struct XofContext(XofInner);
+
+impl XofContext {
+
+    /// Updates the `XofContext` with the given byte array `data`.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.0 {
+            XofInner::Shake128(ctx) => sha3::digest::Update::update(ctx, data),
+            XofInner::Shake256(ctx) => sha3::digest::Update::update(ctx, data),
+        }
+    }
+
+    /// Returns a `Hash` of the requested length `out_len` (in bytes) from the data in the
+    /// `XofContext`. Consumes the `XofContext` so it cannot be reused after calling finish.
+    /// The same input and the same `out_len` always yield the same bytes.
+    pub fn finish(self, out_len: usize) -> Hash {
+        use sha3::digest::{ExtendableOutput, XofReader};
+
+        let mut out = vec![0u8; out_len];
+        match self.0 {
+            XofInner::Shake128(ctx) => XofReader::read(&mut ctx.finalize_xof(), &mut out),
+            XofInner::Shake256(ctx) => XofReader::read(&mut ctx.finalize_xof(), &mut out),
+        }
+        Hash(out)
+    }
+
+}
+
+
+/// The result of a compile-time hash computed via `Hashing::const_hash`. The variant holding
+/// the digest depends on the algorithm that produced it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ConstHash {
+    /// A 32 byte digest, produced by `Hashing::Sha256`.
+    Bytes32([u8; 32]),
+    /// A 64 byte digest, produced by `Hashing::Sha512`.
+    Bytes64([u8; 64]),
+}
+
+impl ConstHash {
+
+    /// Returns a reference to the digest bytes.
+    pub const fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Bytes32(b) => b,
+            Self::Bytes64(b) => b,
+        }
     }
 
 }
@@ -115,21 +272,21 @@ impl HashContext {
 
 /// A hash value which holds the message digest produced by one of the `Hashing` algorithms.
 /// Supports formatting as a byte array, byte vector or a hexadecimal string.
-#[derive(Clone, Copy)]
-pub struct Hash(r_digest::Digest);
+#[derive(Clone)]
+pub struct Hash(Vec<u8>);
 
 impl Hash {
 
     /// Returns a reference to the hash value bytes.
     #[inline]
     pub fn as_bytes(&self) -> &[u8] {
-        self.0.as_ref()
+        &self.0
     }
 
     /// Returns the hash value as a vector of bytes.
     #[inline]
     pub fn to_vec(&self) -> Vec<u8> {
-        self.as_bytes().to_vec()
+        self.0.clone()
     }
 
     /// Returns the hash value as a hexadecimal string.
@@ -138,6 +295,27 @@ impl Hash {
         hex::encode(self.as_bytes())
     }
 
+    /// Returns the hash value as a stack-allocated `[u8; N]`, avoiding the heap allocation of
+    /// `to_vec`. Fails if `N` doesn't match the digest length produced by the algorithm that
+    /// created this `Hash`.
+    pub fn to_array<const N: usize>(&self) -> Result<[u8; N], TryFromSliceError> {
+        self.as_bytes().try_into()
+    }
+
+    /// Returns the hash value as a `[u8; 32]`. Fails if the digest isn't 32 bytes long, e.g.
+    /// because it was produced by `Sha384`, `Sha512` or a `Shake` XOF of a different length.
+    #[inline]
+    pub fn to_bytes32(&self) -> Result<[u8; 32], TryFromSliceError> {
+        self.to_array()
+    }
+
+    /// Returns the hash value as a `[u8; 64]`. Fails if the digest isn't 64 bytes long, e.g.
+    /// because it was produced by `Sha256` or a `Shake` XOF of a different length.
+    #[inline]
+    pub fn to_bytes64(&self) -> Result<[u8; 64], TryFromSliceError> {
+        self.to_array()
+    }
+
 }
 
 
@@ -145,10 +323,11 @@ impl Hash {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Hashing::{Sha1, Sha256, Sha384, Sha512, Sha512_256};
+    use crate::Hashing::{Sha1, Sha256, Sha384, Sha512, Sha512_256, Sha3_224, Sha3_256, Sha3_384, Sha3_512, Shake128, Shake256, Blake2b512, Blake2s256};
     use std::fs::File;
     use std::io::Write;
     use hex::ToHex;
+    use sha3::Digest;
 
     const DATA_TO_DIGEST: &[u8] = b"Hello, World!";
     const FILE_NAME: &str = "testfile.txt";
@@ -250,11 +429,197 @@ mod tests {
         create_test_file();
 
         let expected = r_digest::digest(&r_digest::SHA256, DATA_TO_DIGEST);
-        let result = Sha256.hash_file(FILE_NAME);
+        let result = Sha256.hash_file(FILE_NAME).unwrap();
+
+        assert_eq!(result.as_bytes(), expected.as_ref());
+        assert_eq!(result.to_vec(), expected.as_ref().to_vec());
+        assert_eq!(result.to_hex(), expected.encode_hex::<String>());
+    }
+
+    #[test]
+    fn sha256_digest_file_missing() {
+        let result = Sha256.hash_file("no-such-file-for-fluent-hash-tests.txt");
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn sha256_digest_reader() {
+        let expected = r_digest::digest(&r_digest::SHA256, DATA_TO_DIGEST);
+        let result = Sha256.hash_reader(DATA_TO_DIGEST).unwrap();
 
         assert_eq!(result.as_bytes(), expected.as_ref());
         assert_eq!(result.to_vec(), expected.as_ref().to_vec());
         assert_eq!(result.to_hex(), expected.encode_hex::<String>());
     }
 
+    #[test]
+    fn sha3_224_digest() {
+        let expected = sha3::Sha3_224::digest(DATA_TO_DIGEST);
+        let result = Sha3_224.hash(DATA_TO_DIGEST);
+
+        assert_eq!(result.as_bytes(), expected.as_slice());
+        assert_eq!(result.to_vec(), expected.to_vec());
+        assert_eq!(result.to_hex(), hex::encode(expected));
+    }
+
+    #[test]
+    fn sha3_256_digest() {
+        let expected = sha3::Sha3_256::digest(DATA_TO_DIGEST);
+        let result = Sha3_256.hash(DATA_TO_DIGEST);
+
+        assert_eq!(result.as_bytes(), expected.as_slice());
+        assert_eq!(result.to_vec(), expected.to_vec());
+        assert_eq!(result.to_hex(), hex::encode(expected));
+    }
+
+    #[test]
+    fn sha3_384_digest() {
+        let expected = sha3::Sha3_384::digest(DATA_TO_DIGEST);
+        let result = Sha3_384.hash(DATA_TO_DIGEST);
+
+        assert_eq!(result.as_bytes(), expected.as_slice());
+        assert_eq!(result.to_vec(), expected.to_vec());
+        assert_eq!(result.to_hex(), hex::encode(expected));
+    }
+
+    #[test]
+    fn sha3_512_digest() {
+        let expected = sha3::Sha3_512::digest(DATA_TO_DIGEST);
+        let result = Sha3_512.hash(DATA_TO_DIGEST);
+
+        assert_eq!(result.as_bytes(), expected.as_slice());
+        assert_eq!(result.to_vec(), expected.to_vec());
+        assert_eq!(result.to_hex(), hex::encode(expected));
+    }
+
+    #[test]
+    fn sha3_256_context() {
+        let expected = sha3::Sha3_256::digest(DATA_TO_DIGEST);
+
+        let mut ctx = Sha3_256.new_context();
+        ctx.update(DATA_TO_DIGEST);
+        let result = ctx.finish();
+
+        assert_eq!(result.as_bytes(), expected.as_slice());
+    }
+
+    #[test]
+    fn shake256_xof_length() {
+        let mut ctx = Shake256.new_xof_context();
+        ctx.update(DATA_TO_DIGEST);
+        let result = ctx.finish(64);
+
+        assert_eq!(result.as_bytes().len(), 64);
+        assert_eq!(result.to_vec().len(), 64);
+    }
+
+    #[test]
+    fn shake128_xof_is_deterministic() {
+        let mut ctx1 = Shake128.new_xof_context();
+        ctx1.update(DATA_TO_DIGEST);
+        let result1 = ctx1.finish(32);
+
+        let mut ctx2 = Shake128.new_xof_context();
+        ctx2.update(DATA_TO_DIGEST);
+        let result2 = ctx2.finish(32);
+
+        assert_eq!(result1.as_bytes(), result2.as_bytes());
+    }
+
+    #[test]
+    fn blake2b512_digest() {
+        let expected = blake2::Blake2b512::digest(DATA_TO_DIGEST);
+        let result = Blake2b512.hash(DATA_TO_DIGEST);
+
+        assert_eq!(result.as_bytes(), expected.as_slice());
+        assert_eq!(result.to_vec(), expected.to_vec());
+        assert_eq!(result.to_hex(), hex::encode(expected));
+    }
+
+    #[test]
+    fn blake2s256_digest() {
+        let expected = blake2::Blake2s256::digest(DATA_TO_DIGEST);
+        let result = Blake2s256.hash(DATA_TO_DIGEST);
+
+        assert_eq!(result.as_bytes(), expected.as_slice());
+        assert_eq!(result.to_vec(), expected.to_vec());
+        assert_eq!(result.to_hex(), hex::encode(expected));
+    }
+
+    #[test]
+    fn blake2b512_context() {
+        let expected = blake2::Blake2b512::digest(DATA_TO_DIGEST);
+
+        let mut ctx = Blake2b512.new_context();
+        ctx.update(DATA_TO_DIGEST);
+        let result = ctx.finish();
+
+        assert_eq!(result.as_bytes(), expected.as_slice());
+    }
+
+    #[test]
+    fn const_hash_sha256_matches_runtime() {
+        const RESULT: ConstHash = Sha256.const_hash(DATA_TO_DIGEST);
+
+        let expected = r_digest::digest(&r_digest::SHA256, DATA_TO_DIGEST);
+
+        assert_eq!(RESULT.as_bytes(), expected.as_ref());
+    }
+
+    #[test]
+    fn const_hash_sha512_matches_runtime() {
+        const RESULT: ConstHash = Sha512.const_hash(DATA_TO_DIGEST);
+
+        let expected = r_digest::digest(&r_digest::SHA512, DATA_TO_DIGEST);
+
+        assert_eq!(RESULT.as_bytes(), expected.as_ref());
+    }
+
+    #[test]
+    fn const_hash_sha256_multi_block_matches_runtime() {
+        const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog, and then does it again and again until the message spans more than one block.";
+        const RESULT: ConstHash = Sha256.const_hash(DATA);
+
+        let expected = r_digest::digest(&r_digest::SHA256, DATA);
+
+        assert_eq!(RESULT.as_bytes(), expected.as_ref());
+    }
+
+    #[test]
+    fn sha256_to_bytes32() {
+        let expected = r_digest::digest(&r_digest::SHA256, DATA_TO_DIGEST);
+        let result = Sha256.hash(DATA_TO_DIGEST);
+
+        assert_eq!(result.to_bytes32().unwrap().as_slice(), expected.as_ref());
+    }
+
+    #[test]
+    fn sha256_to_bytes64_fails() {
+        let result = Sha256.hash(DATA_TO_DIGEST);
+
+        assert!(result.to_bytes64().is_err());
+    }
+
+    #[test]
+    fn sha512_to_array() {
+        let expected = r_digest::digest(&r_digest::SHA512, DATA_TO_DIGEST);
+        let result = Sha512.hash(DATA_TO_DIGEST);
+
+        assert_eq!(result.to_array::<64>().unwrap().as_slice(), expected.as_ref());
+    }
+
+    #[test]
+    fn shake256_xof_extends_prefix() {
+        let mut short_ctx = Shake256.new_xof_context();
+        short_ctx.update(DATA_TO_DIGEST);
+        let short = short_ctx.finish(16);
+
+        let mut long_ctx = Shake256.new_xof_context();
+        long_ctx.update(DATA_TO_DIGEST);
+        let long = long_ctx.finish(32);
+
+        assert_eq!(short.as_bytes(), &long.as_bytes()[..16]);
+    }
+
 }
\ No newline at end of file
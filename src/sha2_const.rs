@@ -0,0 +1,277 @@
+// Copyright 2023 Web3 Developer @ Web3Developer.io
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A pure-Rust, `const fn`-compatible implementation of the SHA-256 and SHA-512 compression
+//! functions. `ring` cannot run in a `const` context, so [`crate::Hashing::const_hash`] hashes
+//! through this module instead whenever a digest is needed at compile time.
+
+const H256: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a,
+    0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const K256: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const H512: [u64; 8] = [
+    0x6a09e667f3bcc908, 0xbb67ae8584caa73b, 0x3c6ef372fe94f82b, 0xa54ff53a5f1d36f1,
+    0x510e527fade682d1, 0x9b05688c2b3e6c1f, 0x1f83d9abfb41bd6b, 0x5be0cd19137e2179,
+];
+
+const K512: [u64; 80] = [
+    0x428a2f98d728ae22, 0x7137449123ef65cd, 0xb5c0fbcfec4d3b2f, 0xe9b5dba58189dbbc,
+    0x3956c25bf348b538, 0x59f111f1b605d019, 0x923f82a4af194f9b, 0xab1c5ed5da6d8118,
+    0xd807aa98a3030242, 0x12835b0145706fbe, 0x243185be4ee4b28c, 0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f, 0x80deb1fe3b1696b1, 0x9bdc06a725c71235, 0xc19bf174cf692694,
+    0xe49b69c19ef14ad2, 0xefbe4786384f25e3, 0x0fc19dc68b8cd5b5, 0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275, 0x4a7484aa6ea6e483, 0x5cb0a9dcbd41fbd4, 0x76f988da831153b5,
+    0x983e5152ee66dfab, 0xa831c66d2db43210, 0xb00327c898fb213f, 0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2, 0xd5a79147930aa725, 0x06ca6351e003826f, 0x142929670a0e6e70,
+    0x27b70a8546d22ffc, 0x2e1b21385c26c926, 0x4d2c6dfc5ac42aed, 0x53380d139d95b3df,
+    0x650a73548baf63de, 0x766a0abb3c77b2a8, 0x81c2c92e47edaee6, 0x92722c851482353b,
+    0xa2bfe8a14cf10364, 0xa81a664bbc423001, 0xc24b8b70d0f89791, 0xc76c51a30654be30,
+    0xd192e819d6ef5218, 0xd69906245565a910, 0xf40e35855771202a, 0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8, 0x1e376c085141ab53, 0x2748774cdf8eeb99, 0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63, 0x4ed8aa4ae3418acb, 0x5b9cca4f7763e373, 0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc, 0x78a5636f43172f60, 0x84c87814a1f0ab72, 0x8cc702081a6439ec,
+    0x90befffa23631e28, 0xa4506cebde82bde9, 0xbef9a3f7b2c67915, 0xc67178f2e372532b,
+    0xca273eceea26619c, 0xd186b8c721c0c207, 0xeada7dd6cde0eb1e, 0xf57d4f7fee6ed178,
+    0x06f067aa72176fba, 0x0a637dc5a2c898a6, 0x113f9804bef90dae, 0x1b710b35131c471b,
+    0x28db77f523047d84, 0x32caab7b40c72493, 0x3c9ebe0a15c9bebc, 0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6, 0x597f299cfc657e2a, 0x5fcb6fab3ad6faec, 0x6c44198c4a475817,
+];
+
+const fn sha256_block(state: &mut [u32; 8], block: &[u8], offset: usize) {
+    let mut w = [0u32; 64];
+
+    let mut i = 0;
+    while i < 16 {
+        let o = offset + i * 4;
+        w[i] = ((block[o] as u32) << 24)
+            | ((block[o + 1] as u32) << 16)
+            | ((block[o + 2] as u32) << 8)
+            | (block[o + 3] as u32);
+        i += 1;
+    }
+    while i < 64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        i += 1;
+    }
+
+    let (mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h) =
+        (state[0], state[1], state[2], state[3], state[4], state[5], state[6], state[7]);
+
+    let mut t = 0;
+    while t < 64 {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ ((!e) & g);
+        let temp1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(K256[t]).wrapping_add(w[t]);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+
+        t += 1;
+    }
+
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+    state[4] = state[4].wrapping_add(e);
+    state[5] = state[5].wrapping_add(f);
+    state[6] = state[6].wrapping_add(g);
+    state[7] = state[7].wrapping_add(h);
+}
+
+const fn sha512_block(state: &mut [u64; 8], block: &[u8], offset: usize) {
+    let mut w = [0u64; 80];
+
+    let mut i = 0;
+    while i < 16 {
+        let o = offset + i * 8;
+        w[i] = ((block[o] as u64) << 56)
+            | ((block[o + 1] as u64) << 48)
+            | ((block[o + 2] as u64) << 40)
+            | ((block[o + 3] as u64) << 32)
+            | ((block[o + 4] as u64) << 24)
+            | ((block[o + 5] as u64) << 16)
+            | ((block[o + 6] as u64) << 8)
+            | (block[o + 7] as u64);
+        i += 1;
+    }
+    while i < 80 {
+        let s0 = w[i - 15].rotate_right(1) ^ w[i - 15].rotate_right(8) ^ (w[i - 15] >> 7);
+        let s1 = w[i - 2].rotate_right(19) ^ w[i - 2].rotate_right(61) ^ (w[i - 2] >> 6);
+        w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        i += 1;
+    }
+
+    let (mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h) =
+        (state[0], state[1], state[2], state[3], state[4], state[5], state[6], state[7]);
+
+    let mut t = 0;
+    while t < 80 {
+        let s1 = e.rotate_right(14) ^ e.rotate_right(18) ^ e.rotate_right(41);
+        let ch = (e & f) ^ ((!e) & g);
+        let temp1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(K512[t]).wrapping_add(w[t]);
+        let s0 = a.rotate_right(28) ^ a.rotate_right(34) ^ a.rotate_right(39);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+
+        t += 1;
+    }
+
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+    state[4] = state[4].wrapping_add(e);
+    state[5] = state[5].wrapping_add(f);
+    state[6] = state[6].wrapping_add(g);
+    state[7] = state[7].wrapping_add(h);
+}
+
+/// Computes the SHA-256 digest of `data` at compile time.
+pub const fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut state = H256;
+    let len = data.len();
+    let full_blocks = len / 64;
+
+    let mut i = 0;
+    while i < full_blocks {
+        sha256_block(&mut state, data, i * 64);
+        i += 1;
+    }
+
+    let rem = len - full_blocks * 64;
+    let mut last = [0u8; 128];
+    let mut j = 0;
+    while j < rem {
+        last[j] = data[full_blocks * 64 + j];
+        j += 1;
+    }
+    last[rem] = 0x80;
+
+    let total_blocks: usize = if rem < 56 { 1 } else { 2 };
+    let bit_len = (len as u64) * 8;
+    let len_pos = total_blocks * 64 - 8;
+    let len_bytes = bit_len.to_be_bytes();
+    let mut k = 0;
+    while k < 8 {
+        last[len_pos + k] = len_bytes[k];
+        k += 1;
+    }
+
+    let mut blk = 0;
+    while blk < total_blocks {
+        sha256_block(&mut state, &last, blk * 64);
+        blk += 1;
+    }
+
+    let mut out = [0u8; 32];
+    let mut n = 0;
+    while n < 8 {
+        let bytes = state[n].to_be_bytes();
+        out[n * 4] = bytes[0];
+        out[n * 4 + 1] = bytes[1];
+        out[n * 4 + 2] = bytes[2];
+        out[n * 4 + 3] = bytes[3];
+        n += 1;
+    }
+    out
+}
+
+/// Computes the SHA-512 digest of `data` at compile time.
+pub const fn sha512(data: &[u8]) -> [u8; 64] {
+    let mut state = H512;
+    let len = data.len();
+    let full_blocks = len / 128;
+
+    let mut i = 0;
+    while i < full_blocks {
+        sha512_block(&mut state, data, i * 128);
+        i += 1;
+    }
+
+    let rem = len - full_blocks * 128;
+    let mut last = [0u8; 256];
+    let mut j = 0;
+    while j < rem {
+        last[j] = data[full_blocks * 128 + j];
+        j += 1;
+    }
+    last[rem] = 0x80;
+
+    let total_blocks: usize = if rem < 112 { 1 } else { 2 };
+    // SHA-512 reserves 128 bits for the message length; only the low 64 bits of the bit count
+    // are written here, so the high 64 bits are always left zero.
+    let bit_len = (len as u64) * 8;
+    let len_pos = total_blocks * 128 - 8;
+    let len_bytes = bit_len.to_be_bytes();
+    let mut k = 0;
+    while k < 8 {
+        last[len_pos + k] = len_bytes[k];
+        k += 1;
+    }
+
+    let mut blk = 0;
+    while blk < total_blocks {
+        sha512_block(&mut state, &last, blk * 128);
+        blk += 1;
+    }
+
+    let mut out = [0u8; 64];
+    let mut n = 0;
+    while n < 8 {
+        let bytes = state[n].to_be_bytes();
+        out[n * 8] = bytes[0];
+        out[n * 8 + 1] = bytes[1];
+        out[n * 8 + 2] = bytes[2];
+        out[n * 8 + 3] = bytes[3];
+        out[n * 8 + 4] = bytes[4];
+        out[n * 8 + 5] = bytes[5];
+        out[n * 8 + 6] = bytes[6];
+        out[n * 8 + 7] = bytes[7];
+        n += 1;
+    }
+    out
+}